@@ -0,0 +1,316 @@
+//! First-class command-line surface for the agent.
+//!
+//! Gives `main` a real flag parser: typed flags (int/float/string/bool)
+//! with defaults and help text, plus flags that accept either a single
+//! `u8` or an inclusive range (e.g. `--coverage-level 1..3`). Output
+//! mode (colorized vs. plain) is chosen automatically from whether
+//! stdout is a TTY. The analysis root is not a flag; `main` always
+//! analyzes the current directory.
+
+use std::collections::HashMap;
+use std::ops::RangeInclusive;
+
+/// A parsed flag value.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ArgValue {
+    Int(i64),
+    Float(f64),
+    Str(String),
+    Bool(bool),
+    U8OrRange(U8OrRange),
+}
+
+/// Either a single `u8` or an inclusive range of `u8`, as accepted by
+/// flags like `--coverage-level`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum U8OrRange {
+    Single(u8),
+    Range(u8, u8),
+}
+
+impl U8OrRange {
+    fn parse(raw: &str) -> Result<Self, String> {
+        if let Some((lo, hi)) = raw.split_once("..") {
+            let lo: u8 = lo
+                .parse()
+                .map_err(|_| format!("invalid range start in '{}'", raw))?;
+            let hi: u8 = hi
+                .parse()
+                .map_err(|_| format!("invalid range end in '{}'", raw))?;
+            if lo > hi {
+                return Err(format!("range start {} is after end {} in '{}'", lo, hi, raw));
+            }
+            Ok(U8OrRange::Range(lo, hi))
+        } else {
+            raw.parse()
+                .map(U8OrRange::Single)
+                .map_err(|_| format!("'{}' is not a u8 or a u8 range", raw))
+        }
+    }
+
+    fn within(&self, bounds: &RangeInclusive<u8>) -> bool {
+        match self {
+            U8OrRange::Single(v) => bounds.contains(v),
+            U8OrRange::Range(lo, hi) => bounds.contains(lo) && bounds.contains(hi),
+        }
+    }
+}
+
+/// The kind of value a flag accepts, carrying its default.
+#[derive(Debug, Clone)]
+pub enum ArgKind {
+    Int(i64),
+    Float(f64),
+    Str(String),
+    Bool(bool),
+    /// A u8-or-range flag, with the declared valid bounds used both to
+    /// validate supplied values and to render help text.
+    U8OrRange(RangeInclusive<u8>, U8OrRange),
+}
+
+/// Declares one flag: its name, help text, and default/kind.
+#[derive(Debug, Clone)]
+pub struct ArgSpec {
+    pub name: &'static str,
+    pub help: &'static str,
+    pub kind: ArgKind,
+}
+
+impl ArgSpec {
+    fn help_line(&self) -> String {
+        match &self.kind {
+            ArgKind::U8OrRange(bounds, _) => format!(
+                "  --{} <N|N..M>  {} (between {} and {})",
+                self.name,
+                self.help,
+                bounds.start(),
+                bounds.end()
+            ),
+            _ => format!("  --{} <value>  {}", self.name, self.help),
+        }
+    }
+}
+
+/// Builds up the set of accepted flags, then parses `argv` against it.
+#[derive(Debug, Default, Clone)]
+pub struct Cli {
+    specs: Vec<ArgSpec>,
+}
+
+impl Cli {
+    pub fn new() -> Self {
+        Cli { specs: Vec::new() }
+    }
+
+    pub fn arg(mut self, spec: ArgSpec) -> Self {
+        self.specs.push(spec);
+        self
+    }
+
+    pub fn help_text(&self) -> String {
+        self.specs
+            .iter()
+            .map(ArgSpec::help_line)
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    /// Parses `argv` (flag arguments only, no program name) into
+    /// [`ParsedArgs`], validating `U8OrRange` values against their
+    /// declared bounds.
+    pub fn parse(&self, argv: &[String]) -> Result<ParsedArgs, String> {
+        let mut values: HashMap<String, ArgValue> = self
+            .specs
+            .iter()
+            .map(|spec| (spec.name.to_string(), default_value(&spec.kind)))
+            .collect();
+
+        let mut iter = argv.iter();
+        while let Some(token) = iter.next() {
+            let name = token
+                .strip_prefix("--")
+                .ok_or_else(|| format!("expected flag starting with '--', got '{}'", token))?;
+
+            let spec = self
+                .specs
+                .iter()
+                .find(|spec| spec.name == name)
+                .ok_or_else(|| format!("unknown flag '--{}'", name))?;
+
+            let raw = iter
+                .next()
+                .ok_or_else(|| format!("flag '--{}' expects a value", name))?;
+
+            let value = match &spec.kind {
+                ArgKind::Int(_) => ArgValue::Int(
+                    raw.parse()
+                        .map_err(|_| format!("'--{}' expects an integer, got '{}'", name, raw))?,
+                ),
+                ArgKind::Float(_) => ArgValue::Float(
+                    raw.parse()
+                        .map_err(|_| format!("'--{}' expects a float, got '{}'", name, raw))?,
+                ),
+                ArgKind::Str(_) => ArgValue::Str(raw.clone()),
+                ArgKind::Bool(_) => ArgValue::Bool(
+                    raw.parse()
+                        .map_err(|_| format!("'--{}' expects true/false, got '{}'", name, raw))?,
+                ),
+                ArgKind::U8OrRange(bounds, _) => {
+                    let parsed = U8OrRange::parse(raw)?;
+                    if !parsed.within(bounds) {
+                        return Err(format!(
+                            "'--{}' value {:?} is outside the valid range {}..{}",
+                            name,
+                            parsed,
+                            bounds.start(),
+                            bounds.end()
+                        ));
+                    }
+                    ArgValue::U8OrRange(parsed)
+                }
+            };
+
+            values.insert(name.to_string(), value);
+        }
+
+        Ok(ParsedArgs { values })
+    }
+}
+
+fn default_value(kind: &ArgKind) -> ArgValue {
+    match kind {
+        ArgKind::Int(d) => ArgValue::Int(*d),
+        ArgKind::Float(d) => ArgValue::Float(*d),
+        ArgKind::Str(d) => ArgValue::Str(d.clone()),
+        ArgKind::Bool(d) => ArgValue::Bool(*d),
+        ArgKind::U8OrRange(_, d) => ArgValue::U8OrRange(*d),
+    }
+}
+
+/// Flag values resolved from argv, falling back to declared defaults.
+#[derive(Debug)]
+pub struct ParsedArgs {
+    values: HashMap<String, ArgValue>,
+}
+
+impl ParsedArgs {
+    pub fn get_int(&self, name: &str) -> Option<i64> {
+        match self.values.get(name) {
+            Some(ArgValue::Int(v)) => Some(*v),
+            _ => None,
+        }
+    }
+
+    pub fn get_float(&self, name: &str) -> Option<f64> {
+        match self.values.get(name) {
+            Some(ArgValue::Float(v)) => Some(*v),
+            _ => None,
+        }
+    }
+
+    pub fn get_str(&self, name: &str) -> Option<&str> {
+        match self.values.get(name) {
+            Some(ArgValue::Str(v)) => Some(v.as_str()),
+            _ => None,
+        }
+    }
+
+    pub fn get_bool(&self, name: &str) -> Option<bool> {
+        match self.values.get(name) {
+            Some(ArgValue::Bool(v)) => Some(*v),
+            _ => None,
+        }
+    }
+
+    pub fn get_u8_or_range(&self, name: &str) -> Option<U8OrRange> {
+        match self.values.get(name) {
+            Some(ArgValue::U8OrRange(v)) => Some(*v),
+            _ => None,
+        }
+    }
+}
+
+/// Whether output should be colorized. Colorized, human-oriented output
+/// is used when stdout is a terminal; plain, machine-readable output is
+/// used otherwise (e.g. piped into a file or another tool).
+pub fn use_color() -> bool {
+    atty::is(atty::Stream::Stdout)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn argv(args: &[&str]) -> Vec<String> {
+        args.iter().map(|s| s.to_string()).collect()
+    }
+
+    fn test_cli() -> Cli {
+        Cli::new()
+            .arg(ArgSpec {
+                name: "jobs",
+                help: "worker count",
+                kind: ArgKind::Int(0),
+            })
+            .arg(ArgSpec {
+                name: "verbose",
+                help: "be verbose",
+                kind: ArgKind::Bool(false),
+            })
+            .arg(ArgSpec {
+                name: "threshold",
+                help: "a float threshold",
+                kind: ArgKind::Float(0.5),
+            })
+            .arg(ArgSpec {
+                name: "coverage-level",
+                help: "coverage level(s)",
+                kind: ArgKind::U8OrRange(1..=3, U8OrRange::Single(1)),
+            })
+    }
+
+    #[test]
+    fn unset_flags_fall_back_to_declared_defaults() {
+        let args = test_cli().parse(&argv(&[])).unwrap();
+        assert_eq!(args.get_int("jobs"), Some(0));
+        assert_eq!(args.get_bool("verbose"), Some(false));
+        assert_eq!(args.get_float("threshold"), Some(0.5));
+        assert_eq!(args.get_u8_or_range("coverage-level"), Some(U8OrRange::Single(1)));
+    }
+
+    #[test]
+    fn typed_flags_parse_to_their_declared_kind() {
+        let args = test_cli()
+            .parse(&argv(&["--jobs", "4", "--verbose", "true", "--threshold", "0.9"]))
+            .unwrap();
+        assert_eq!(args.get_int("jobs"), Some(4));
+        assert_eq!(args.get_bool("verbose"), Some(true));
+        assert_eq!(args.get_float("threshold"), Some(0.9));
+    }
+
+    #[test]
+    fn coverage_level_accepts_single_value_or_range() {
+        let single = test_cli().parse(&argv(&["--coverage-level", "2"])).unwrap();
+        assert_eq!(single.get_u8_or_range("coverage-level"), Some(U8OrRange::Single(2)));
+
+        let range = test_cli().parse(&argv(&["--coverage-level", "1..3"])).unwrap();
+        assert_eq!(range.get_u8_or_range("coverage-level"), Some(U8OrRange::Range(1, 3)));
+    }
+
+    #[test]
+    fn coverage_level_outside_declared_bounds_is_rejected() {
+        let result = test_cli().parse(&argv(&["--coverage-level", "9"]));
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn unknown_flag_is_rejected() {
+        assert!(test_cli().parse(&argv(&["--nope", "1"])).is_err());
+    }
+
+    #[test]
+    fn help_text_renders_declared_range_for_u8_or_range_flags() {
+        let help = test_cli().help_text();
+        assert!(help.contains("between 1 and 3"));
+    }
+}
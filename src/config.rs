@@ -0,0 +1,144 @@
+//! Verification configuration.
+//!
+//! Analysis thresholds live in an explicit config object, the way
+//! `SensorConfig` is threaded through `classify_reading`. This gives
+//! the agent the same shape of config object — loaded once from
+//! TOML, validated up front, then threaded by reference through every
+//! analyzer — so a misconfigured run fails before it silently weakens
+//! certification evidence.
+
+use std::fs;
+use std::path::Path;
+
+use serde::Deserialize;
+use toml::Value;
+
+/// Thresholds and scope for a verification run, analogous to how
+/// `&SensorConfig` is threaded through `classify_reading` and
+/// `process_readings`.
+#[derive(Debug, Clone, Deserialize)]
+pub struct VerificationConfig {
+    /// DO-178C objective level, 'A' (most stringent) through 'E'.
+    pub objective_level: char,
+    /// Minimum required decision/MC-DC coverage, as a fraction of 1.0.
+    pub min_coverage: f64,
+    /// Number of unhandled `Err` arms tolerated before flagging a unit.
+    pub allowed_unhandled_err_arms: usize,
+    #[serde(default)]
+    pub include_globs: Vec<String>,
+    #[serde(default)]
+    pub exclude_globs: Vec<String>,
+}
+
+impl VerificationConfig {
+    /// Loads and validates a config from a TOML file, normalizing keys
+    /// to lowercase first so `Objective_Level` and `objective_level`
+    /// are equivalent.
+    pub fn load(path: &Path) -> Result<Self, String> {
+        let raw = fs::read_to_string(path)
+            .map_err(|e| format!("failed to read config {}: {}", path.display(), e))?;
+
+        let value: Value = raw
+            .parse()
+            .map_err(|e| format!("failed to parse config {}: {}", path.display(), e))?;
+        let normalized = lowercase_keys(value);
+
+        let config: VerificationConfig = normalized
+            .try_into()
+            .map_err(|e| format!("invalid config {}: {}", path.display(), e))?;
+
+        if !config.is_valid() {
+            return Err(format!(
+                "config {} failed validation: objective_level must be A-E and min_coverage must be within 0.0..=1.0",
+                path.display()
+            ));
+        }
+
+        Ok(config)
+    }
+
+    /// Fails fast on an invalid config so a bad file is never
+    /// discovered partway through a run.
+    pub fn is_valid(&self) -> bool {
+        matches!(self.objective_level, 'A'..='E') && (0.0..=1.0).contains(&self.min_coverage)
+    }
+}
+
+/// Recursively lowercases every table key in a parsed TOML value.
+fn lowercase_keys(value: Value) -> Value {
+    match value {
+        Value::Table(table) => Value::Table(
+            table
+                .into_iter()
+                .map(|(key, value)| (key.to_lowercase(), lowercase_keys(value)))
+                .collect(),
+        ),
+        Value::Array(items) => Value::Array(items.into_iter().map(lowercase_keys).collect()),
+        other => other,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    fn config(objective_level: char, min_coverage: f64) -> VerificationConfig {
+        VerificationConfig {
+            objective_level,
+            min_coverage,
+            allowed_unhandled_err_arms: 0,
+            include_globs: Vec::new(),
+            exclude_globs: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn is_valid_accepts_objective_level_a_through_e_and_coverage_in_unit_range() {
+        assert!(config('A', 1.0).is_valid());
+        assert!(config('E', 0.0).is_valid());
+    }
+
+    #[test]
+    fn is_valid_rejects_objective_level_outside_a_through_e() {
+        assert!(!config('F', 0.5).is_valid());
+    }
+
+    #[test]
+    fn is_valid_rejects_min_coverage_outside_zero_to_one() {
+        assert!(!config('A', 1.5).is_valid());
+        assert!(!config('A', -0.1).is_valid());
+    }
+
+    fn write_temp_config(contents: &str) -> std::path::PathBuf {
+        static COUNTER: AtomicUsize = AtomicUsize::new(0);
+        let id = COUNTER.fetch_add(1, Ordering::Relaxed);
+        let path = std::env::temp_dir().join(format!("do178c_agent_config_test_{}_{}.toml", std::process::id(), id));
+        fs::write(&path, contents).unwrap();
+        path
+    }
+
+    #[test]
+    fn load_normalizes_keys_to_lowercase() {
+        let path = write_temp_config(
+            "Objective_Level = \"B\"\nMin_Coverage = 0.8\nAllowed_Unhandled_Err_Arms = 2\n",
+        );
+        let config = VerificationConfig::load(&path).unwrap();
+        fs::remove_file(&path).unwrap();
+
+        assert_eq!(config.objective_level, 'B');
+        assert_eq!(config.min_coverage, 0.8);
+        assert_eq!(config.allowed_unhandled_err_arms, 2);
+    }
+
+    #[test]
+    fn load_fails_fast_on_invalid_config() {
+        let path = write_temp_config(
+            "objective_level = \"Z\"\nmin_coverage = 0.8\nallowed_unhandled_err_arms = 0\n",
+        );
+        let result = VerificationConfig::load(&path);
+        fs::remove_file(&path).unwrap();
+
+        assert!(result.is_err());
+    }
+}
@@ -0,0 +1,180 @@
+//! Parallel analysis driver.
+//!
+//! The agent walks a set of source units (files, eventually functions)
+//! and runs the analyzer pipeline over each one independently. For real
+//! DO-178C code bases this set can run into the thousands, so the driver
+//! fans the work out across a `rayon` pool. Each unit is analyzed in
+//! isolation with no shared mutable state, so the resulting vector is
+//! bit-identical to a single-threaded run regardless of how the pool
+//! schedules the work — certification evidence must be reproducible.
+//! Traceability scanning happens inside `analyze_unit` itself, so it
+//! rides the same parallel fan-out rather than re-scanning the corpus
+//! sequentially afterwards.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use rayon::prelude::*;
+use rayon::ThreadPoolBuilder;
+
+use crate::config::VerificationConfig;
+use crate::traceability::{self, UnitTrace};
+
+/// A single source file handed to the analyzer pipeline.
+pub struct SourceUnit {
+    pub path: PathBuf,
+    pub source: String,
+}
+
+/// Per-unit analysis output: the basics every analyzer needs (which
+/// unit it came from and its position in the input set, so output
+/// order is deterministic) plus each analyzer's own findings.
+pub struct UnitResult {
+    pub unit_index: usize,
+    pub path: PathBuf,
+    pub function_count: usize,
+    pub trace: UnitTrace,
+    pub unhandled_err_arms: usize,
+    pub exceeds_error_budget: bool,
+}
+
+/// Reads every path into a `SourceUnit`, skipping files that can't be
+/// read (e.g. vanished between discovery and analysis) with a warning
+/// rather than failing the whole run.
+pub fn collect_source_units(paths: &[PathBuf]) -> Vec<SourceUnit> {
+    paths
+        .iter()
+        .filter_map(|path| match fs::read_to_string(path) {
+            Ok(source) => Some(SourceUnit {
+                path: path.clone(),
+                source,
+            }),
+            Err(e) => {
+                eprintln!("Skipping unreadable unit {}: {}", path.display(), e);
+                None
+            }
+        })
+        .collect()
+}
+
+/// Analyzes a single unit against `config`. This is the extension point
+/// every analyzer hangs off of; it must not touch anything outside
+/// `unit` and `config` so that running it on a rayon worker thread is
+/// safe — `config` is immutable and `Sync`, so sharing it across
+/// threads introduces no mutable state.
+fn analyze_unit(unit: &SourceUnit, config: &VerificationConfig) -> UnitResult {
+    let function_count = traceability::function_names(&unit.source).len();
+    let trace = traceability::scan_unit_trace(unit);
+    // Counts every `Err(` occurrence in the source text; handled and
+    // unhandled arms, and `Err(..)` constructions, are not told apart.
+    let unhandled_err_arms = unit.source.matches("Err(").count();
+    let exceeds_error_budget = unhandled_err_arms > config.allowed_unhandled_err_arms;
+
+    UnitResult {
+        unit_index: 0,
+        path: unit.path.clone(),
+        function_count,
+        trace,
+        unhandled_err_arms,
+        exceeds_error_budget,
+    }
+}
+
+/// Runs the analyzer pipeline over every unit against `config`,
+/// optionally pinning the rayon pool to `jobs` threads. Returns results
+/// in input order — a `Vec`'s `par_iter` is index-preserving, so
+/// `collect()` below yields the same ordering as a sequential
+/// `iter().map(...)` would, no matter how threads are scheduled.
+pub fn run_pipeline(
+    units: Vec<SourceUnit>,
+    jobs: Option<usize>,
+    config: &VerificationConfig,
+) -> Vec<UnitResult> {
+    let analyze_all = || {
+        units
+            .par_iter()
+            .enumerate()
+            .map(|(unit_index, unit)| UnitResult {
+                unit_index,
+                ..analyze_unit(unit, config)
+            })
+            .collect()
+    };
+
+    match jobs {
+        Some(n) => ThreadPoolBuilder::new()
+            .num_threads(n)
+            .build()
+            .expect("failed to build rayon pool with requested --jobs count")
+            .install(analyze_all),
+        None => analyze_all(),
+    }
+}
+
+/// Recursively discovers `.rs` files under `root`, used to build the
+/// unit set `run_pipeline` consumes.
+pub fn discover_source_units(root: &Path) -> Vec<PathBuf> {
+    let mut paths = Vec::new();
+    let mut stack = vec![root.to_path_buf()];
+    while let Some(dir) = stack.pop() {
+        let entries = match fs::read_dir(&dir) {
+            Ok(entries) => entries,
+            Err(_) => continue,
+        };
+        for entry in entries.flatten() {
+            let path = entry.path();
+            if path.is_dir() {
+                stack.push(path);
+            } else if path.extension().is_some_and(|ext| ext == "rs") {
+                paths.push(path);
+            }
+        }
+    }
+    paths.sort();
+    paths
+}
+
+/// Filters discovered paths against `config`'s include/exclude globs: a
+/// path must match at least one include glob (or no include globs are
+/// declared, meaning everything is in scope) and no exclude glob.
+pub fn filter_by_globs(paths: Vec<PathBuf>, config: &VerificationConfig) -> Vec<PathBuf> {
+    paths
+        .into_iter()
+        .filter(|path| {
+            let path_str = path.to_string_lossy();
+            let included = config.include_globs.is_empty()
+                || config.include_globs.iter().any(|glob| glob_match(glob, &path_str));
+            let excluded = config.exclude_globs.iter().any(|glob| glob_match(glob, &path_str));
+            included && !excluded
+        })
+        .collect()
+}
+
+/// Minimal glob matcher supporting `*` as a wildcard for any run of
+/// characters; every other character must match literally.
+fn glob_match(pattern: &str, text: &str) -> bool {
+    let parts: Vec<&str> = pattern.split('*').collect();
+    if parts.len() == 1 {
+        return pattern == text;
+    }
+
+    let first = parts[0];
+    let last = parts[parts.len() - 1];
+    if !text.starts_with(first) || !text.ends_with(last) {
+        return false;
+    }
+
+    let mut cursor = first.len();
+    let suffix_start = text.len() - last.len();
+    for middle in &parts[1..parts.len() - 1] {
+        if middle.is_empty() {
+            continue;
+        }
+        match text[cursor..suffix_start.max(cursor)].find(middle) {
+            Some(offset) => cursor += offset + middle.len(),
+            None => return false,
+        }
+    }
+
+    cursor <= suffix_start
+}
@@ -0,0 +1,170 @@
+mod cli;
+mod config;
+mod driver;
+mod ranking;
+mod traceability;
+mod version_sort;
+
+use std::path::Path;
+use std::process;
+
+use cli::{ArgKind, ArgSpec, Cli, U8OrRange};
+use config::VerificationConfig;
+use ranking::{Finding, RankingEngine, RankingRule};
+
+fn build_cli() -> Cli {
+    Cli::new()
+        .arg(ArgSpec {
+            name: "jobs",
+            help: "number of worker threads for the analysis pool (defaults to the rayon global pool)",
+            kind: ArgKind::Int(0),
+        })
+        .arg(ArgSpec {
+            name: "coverage-level",
+            help: "required DO-178C coverage level(s) to check",
+            kind: ArgKind::U8OrRange(1..=3, cli::U8OrRange::Single(1)),
+        })
+        .arg(ArgSpec {
+            name: "config",
+            help: "path to the VerificationConfig TOML file",
+            kind: ArgKind::Str("do178c.toml".to_string()),
+        })
+        .arg(ArgSpec {
+            name: "verbose",
+            help: "print each trace tag's enclosing item and file, not just per-requirement counts",
+            kind: ArgKind::Bool(false),
+        })
+        .arg(ArgSpec {
+            name: "min-coverage",
+            help: "override the config's min_coverage threshold (negative means use the config value)",
+            kind: ArgKind::Float(-1.0),
+        })
+}
+
+fn main() {
+    let cli = build_cli();
+    let argv: Vec<String> = std::env::args().skip(1).collect();
+    if argv.iter().any(|a| a == "--help" || a == "-h") {
+        println!("{}", cli.help_text());
+        return;
+    }
+
+    let args = match cli.parse(&argv) {
+        Ok(args) => args,
+        Err(e) => {
+            eprintln!("error: {}", e);
+            process::exit(1);
+        }
+    };
+
+    let jobs = match args.get_int("jobs") {
+        Some(n) if n > 0 => Some(n as usize),
+        _ => None,
+    };
+    let colorize = cli::use_color();
+    let verbose = args.get_bool("verbose").unwrap_or(false);
+
+    let config_path = Path::new(args.get_str("config").unwrap_or("do178c.toml"));
+    let mut config = match VerificationConfig::load(config_path) {
+        Ok(config) => config,
+        Err(e) => {
+            eprintln!("error: {}", e);
+            process::exit(1);
+        }
+    };
+
+    if let Some(min_coverage) = args.get_float("min-coverage") {
+        if min_coverage >= 0.0 {
+            config.min_coverage = min_coverage;
+        }
+    }
+
+    match args.get_u8_or_range("coverage-level") {
+        Some(U8OrRange::Single(level)) => {
+            print_line(colorize, &format!("checking coverage level {}", level))
+        }
+        Some(U8OrRange::Range(lo, hi)) => {
+            print_line(colorize, &format!("checking coverage levels {} through {}", lo, hi))
+        }
+        None => {}
+    }
+
+    let root = Path::new(".");
+    let discovered = driver::discover_source_units(root);
+    let paths = driver::filter_by_globs(discovered, &config);
+    let units = driver::collect_source_units(&paths);
+
+    let results = driver::run_pipeline(units, jobs, &config);
+
+    let trace_matrix = traceability::merge_trace_matrix(
+        results.iter().map(|result| (result.path.as_path(), &result.trace)),
+    );
+
+    let mut requirement_ids: Vec<String> = trace_matrix.requirement_to_tags.keys().cloned().collect();
+    version_sort::sort_ids(&mut requirement_ids);
+    for requirement_id in &requirement_ids {
+        let tags = &trace_matrix.requirement_to_tags[requirement_id];
+        print_line(colorize, &format!("{}: {} tag(s)", requirement_id, tags.len()));
+        if verbose {
+            for tag in tags {
+                print_line(colorize, &format!("  {} in {}", tag.item_name, tag.path.display()));
+            }
+        }
+    }
+
+    let mut zero_coverage = trace_matrix.zero_coverage_requirements();
+    zero_coverage.sort_by(|a, b| version_sort::compare(a, b));
+    for requirement_id in &zero_coverage {
+        print_line(colorize, &format!("zero coverage: {}", requirement_id));
+    }
+
+    let findings: Vec<Finding> = trace_matrix
+        .untagged_functions
+        .iter()
+        .map(|(path, name)| Finding {
+            path: path.clone(),
+            objective_level: config.objective_level,
+            coverage_gap: 0.0,
+            requirement_trace_missing: true,
+            message: format!("{} has no requirement trace", name),
+        })
+        .collect();
+
+    let engine = RankingEngine::new(vec![
+        RankingRule::RequirementTraceMissing,
+        RankingRule::ObjectiveLevel,
+        RankingRule::CoverageGap,
+        RankingRule::FilePath,
+    ]);
+    for finding in engine.top_n_per_unit(&findings, 5) {
+        print_line(colorize, &format!("finding: {} ({})", finding.message, finding.path.display()));
+    }
+
+    for result in &results {
+        print_line(
+            colorize,
+            &format!(
+                "[{}] {}: {} function(s), {} Err arm(s){}",
+                result.unit_index,
+                result.path.display(),
+                result.function_count,
+                result.unhandled_err_arms,
+                if result.exceeds_error_budget {
+                    " (exceeds allowed_unhandled_err_arms)"
+                } else {
+                    ""
+                }
+            ),
+        );
+    }
+}
+
+/// Prints a line, wrapping it in ANSI color codes when stdout is a TTY
+/// and leaving it plain (machine-readable) otherwise.
+fn print_line(colorize: bool, line: &str) {
+    if colorize {
+        println!("\x1b[36m{}\x1b[0m", line);
+    } else {
+        println!("{}", line);
+    }
+}
@@ -0,0 +1,166 @@
+//! Severity ranking for verification findings.
+//!
+//! The fixture's `AlertLevel { Normal, Warning, Critical }` classifies a
+//! single sensor reading; findings need the analogous idea across an
+//! entire run. Rather than hard-coding one severity order, findings are
+//! ranked by a configurable chain of rules applied in priority order,
+//! each rule breaking ties left by the rules before it — a Level A
+//! project might prioritize MC/DC gaps, while another prioritizes
+//! untraced code.
+
+use std::cmp::Ordering;
+use std::path::PathBuf;
+
+use crate::version_sort;
+
+/// A single verification finding surfaced for one source unit.
+#[derive(Debug, Clone)]
+pub struct Finding {
+    pub path: PathBuf,
+    /// DO-178C objective level the finding applies to, 'A' (most
+    /// critical) through 'E'.
+    pub objective_level: char,
+    /// Decision/MC-DC coverage gap, 0.0 meaning fully covered.
+    pub coverage_gap: f64,
+    /// Whether the finding's item has no requirement trace at all.
+    pub requirement_trace_missing: bool,
+    pub message: String,
+}
+
+/// One criterion in the ranking chain. Rules are applied in the order
+/// given to [`RankingEngine::new`]; each rule only breaks ties left by
+/// the rules before it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RankingRule {
+    ObjectiveLevel,
+    CoverageGap,
+    RequirementTraceMissing,
+    FilePath,
+}
+
+impl RankingRule {
+    fn compare(self, a: &Finding, b: &Finding) -> Ordering {
+        match self {
+            // 'A' is the most critical objective level, so it sorts first.
+            RankingRule::ObjectiveLevel => a.objective_level.cmp(&b.objective_level),
+            // Larger gaps are more severe, so they sort first (descending).
+            RankingRule::CoverageGap => b
+                .coverage_gap
+                .partial_cmp(&a.coverage_gap)
+                .unwrap_or(Ordering::Equal),
+            // Missing a trace entirely is worse than having one.
+            RankingRule::RequirementTraceMissing => b
+                .requirement_trace_missing
+                .cmp(&a.requirement_trace_missing),
+            RankingRule::FilePath => {
+                version_sort::compare(&a.path.to_string_lossy(), &b.path.to_string_lossy())
+            }
+        }
+    }
+}
+
+/// Ranks and groups findings according to a user-supplied rule order.
+pub struct RankingEngine {
+    rules: Vec<RankingRule>,
+}
+
+impl RankingEngine {
+    pub fn new(rules: Vec<RankingRule>) -> Self {
+        RankingEngine { rules }
+    }
+
+    /// Sorts `findings` in place by applying each rule in order, later
+    /// rules breaking ties left by earlier ones.
+    pub fn rank(&self, findings: &mut [Finding]) {
+        findings.sort_by(|a, b| {
+            for rule in &self.rules {
+                let ordering = rule.compare(a, b);
+                if ordering != Ordering::Equal {
+                    return ordering;
+                }
+            }
+            Ordering::Equal
+        });
+    }
+
+    /// Ranks `findings`, then returns at most `n` findings per source
+    /// unit, preserving rank order within each unit.
+    pub fn top_n_per_unit(&self, findings: &[Finding], n: usize) -> Vec<Finding> {
+        let mut ranked = findings.to_vec();
+        self.rank(&mut ranked);
+
+        let mut counts: std::collections::HashMap<PathBuf, usize> = std::collections::HashMap::new();
+        ranked
+            .into_iter()
+            .filter(|finding| {
+                let count = counts.entry(finding.path.clone()).or_insert(0);
+                *count += 1;
+                *count <= n
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn finding(path: &str, objective_level: char, coverage_gap: f64, trace_missing: bool) -> Finding {
+        Finding {
+            path: PathBuf::from(path),
+            objective_level,
+            coverage_gap,
+            requirement_trace_missing: trace_missing,
+            message: String::new(),
+        }
+    }
+
+    #[test]
+    fn earlier_rule_wins_over_later_rule() {
+        let mut findings = vec![
+            finding("b.rs", 'B', 0.9, false),
+            finding("a.rs", 'A', 0.1, false),
+        ];
+        let engine = RankingEngine::new(vec![RankingRule::ObjectiveLevel, RankingRule::CoverageGap]);
+        engine.rank(&mut findings);
+        assert_eq!(findings[0].path, PathBuf::from("a.rs"));
+    }
+
+    #[test]
+    fn later_rule_breaks_tie_left_by_earlier_rule() {
+        let mut findings = vec![
+            finding("b.rs", 'A', 0.1, false),
+            finding("a.rs", 'A', 0.9, false),
+        ];
+        let engine = RankingEngine::new(vec![RankingRule::ObjectiveLevel, RankingRule::CoverageGap]);
+        engine.rank(&mut findings);
+        // Tied on objective level; larger coverage gap is more severe.
+        assert_eq!(findings[0].path, PathBuf::from("a.rs"));
+    }
+
+    #[test]
+    fn rule_order_is_configurable() {
+        let mut findings = vec![
+            finding("a.rs", 'B', 0.0, true),
+            finding("b.rs", 'A', 0.0, false),
+        ];
+        let engine = RankingEngine::new(vec![RankingRule::RequirementTraceMissing, RankingRule::ObjectiveLevel]);
+        engine.rank(&mut findings);
+        // Missing trace outranks objective level under this rule order.
+        assert_eq!(findings[0].path, PathBuf::from("a.rs"));
+    }
+
+    #[test]
+    fn top_n_per_unit_caps_findings_per_path_after_ranking() {
+        let findings = vec![
+            finding("a.rs", 'C', 0.1, false),
+            finding("a.rs", 'A', 0.9, false),
+            finding("a.rs", 'B', 0.5, false),
+        ];
+        let engine = RankingEngine::new(vec![RankingRule::ObjectiveLevel]);
+        let top = engine.top_n_per_unit(&findings, 2);
+        assert_eq!(top.len(), 2);
+        assert_eq!(top[0].objective_level, 'A');
+        assert_eq!(top[1].objective_level, 'B');
+    }
+}
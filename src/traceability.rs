@@ -0,0 +1,183 @@
+//! Requirement-to-code traceability.
+//!
+//! DO-178C requires bidirectional traceability between requirements and
+//! the code that implements them. This module scans source units for
+//! structured comment tags such as `// @satisfies REQ-1234` or
+//! `// @derived REQ-5`, resolves each tag to its enclosing item, and
+//! builds a trace matrix linking requirements to functions.
+
+use std::collections::BTreeMap;
+use std::sync::OnceLock;
+
+use regex::Regex;
+
+use crate::driver::SourceUnit;
+
+/// Kind of traceability claim a tag makes about its enclosing item.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TagKind {
+    Satisfies,
+    Derived,
+    Verifies,
+}
+
+impl TagKind {
+    fn from_str(s: &str) -> Option<Self> {
+        match s {
+            "satisfies" => Some(TagKind::Satisfies),
+            "derived" => Some(TagKind::Derived),
+            "verifies" => Some(TagKind::Verifies),
+            _ => None,
+        }
+    }
+}
+
+/// A single traceability tag found in a source unit.
+#[derive(Debug, Clone)]
+pub struct TraceTag {
+    pub kind: TagKind,
+    pub requirement_id: String,
+    pub item_name: String,
+    pub path: std::path::PathBuf,
+}
+
+/// Requirement coverage, keyed by requirement ID, and the reverse
+/// mapping of which functions carry no requirement tag at all.
+#[derive(Debug, Default)]
+pub struct TraceMatrix {
+    pub requirement_to_tags: BTreeMap<String, Vec<TraceTag>>,
+    pub untagged_functions: Vec<(std::path::PathBuf, String)>,
+}
+
+impl TraceMatrix {
+    /// Requirement IDs that are referenced somewhere (via `@derived` or
+    /// `@verifies`) but never claimed by a `@satisfies` tag anywhere in
+    /// the scanned corpus — traced, but with zero implementation
+    /// coverage.
+    pub fn zero_coverage_requirements(&self) -> Vec<&str> {
+        self.requirement_to_tags
+            .iter()
+            .filter(|(_, tags)| !tags.iter().any(|tag| tag.kind == TagKind::Satisfies))
+            .map(|(requirement_id, _)| requirement_id.as_str())
+            .collect()
+    }
+}
+
+/// Lazily-compiled, process-wide regex for `@satisfies`/`@derived`/
+/// `@verifies` tags. Built once and reused across every file scanned so
+/// the cost of compilation is paid a single time regardless of corpus
+/// size.
+fn tag_regex() -> &'static Regex {
+    static TAG_REGEX: OnceLock<Regex> = OnceLock::new();
+    TAG_REGEX.get_or_init(|| {
+        Regex::new(r"(?m)^\s*//\s*@(satisfies|derived|verifies)\s+(REQ-\d+)").unwrap()
+    })
+}
+
+/// Finds the `fn`/`pub fn` item name that follows the tag's line, i.e.
+/// the item the tag is documenting.
+fn enclosing_item_name(source: &str, tag_line_end: usize) -> Option<String> {
+    let rest = &source[tag_line_end..];
+    for line in rest.lines() {
+        let trimmed = line.trim_start();
+        if let Some(after_fn) = trimmed
+            .strip_prefix("pub fn ")
+            .or_else(|| trimmed.strip_prefix("fn "))
+        {
+            let name = after_fn.split(['(', '<', ' ']).next().unwrap_or("").to_string();
+            if !name.is_empty() {
+                return Some(name);
+            }
+        }
+    }
+    None
+}
+
+/// Scans a single unit for traceability tags, resolving each to its
+/// enclosing function name. Tags with no `fn` item after them are
+/// skipped.
+pub fn scan_unit(unit: &SourceUnit) -> Vec<TraceTag> {
+    let regex = tag_regex();
+    regex
+        .captures_iter(&unit.source)
+        .filter_map(|caps| {
+            let kind = TagKind::from_str(&caps[1])?;
+            let requirement_id = caps[2].to_string();
+            let tag_end = caps.get(0).unwrap().end();
+            let item_name = enclosing_item_name(&unit.source, tag_end)?;
+            Some(TraceTag {
+                kind,
+                requirement_id,
+                item_name,
+                path: unit.path.clone(),
+            })
+        })
+        .collect()
+}
+
+/// Names of every `fn`/`pub fn` item declared in `source`, in source
+/// order. Shared by the traceability scan and the driver's function
+/// count so the two never drift apart on what counts as a function.
+pub fn function_names(source: &str) -> Vec<String> {
+    source
+        .lines()
+        .filter_map(|line| {
+            let trimmed = line.trim_start();
+            trimmed
+                .strip_prefix("pub fn ")
+                .or_else(|| trimmed.strip_prefix("fn "))
+                .and_then(|rest| rest.split(['(', '<', ' ']).next())
+                .filter(|n| !n.is_empty())
+                .map(str::to_string)
+        })
+        .collect()
+}
+
+/// The traceability scan for a single unit: every tag found, and the
+/// names of functions that carry none. Computed once per unit so the
+/// parallel driver can fan this out across units instead of re-scanning
+/// the whole corpus sequentially afterwards.
+pub struct UnitTrace {
+    pub tags: Vec<TraceTag>,
+    pub untagged_functions: Vec<String>,
+}
+
+/// Scans a unit for both its tags and its untagged functions.
+pub fn scan_unit_trace(unit: &SourceUnit) -> UnitTrace {
+    let tags = scan_unit(unit);
+    let tagged_functions: Vec<&str> = tags.iter().map(|t| t.item_name.as_str()).collect();
+    let untagged_functions = function_names(&unit.source)
+        .into_iter()
+        .filter(|name| !tagged_functions.contains(&name.as_str()))
+        .collect();
+
+    UnitTrace {
+        tags,
+        untagged_functions,
+    }
+}
+
+/// Merges per-unit traces — already computed, possibly on different
+/// rayon workers — into a single trace matrix. This step is cheap
+/// (just grouping already-extracted tags), so it stays sequential while
+/// the expensive regex scanning happens in parallel upstream.
+pub fn merge_trace_matrix<'a>(
+    units: impl IntoIterator<Item = (&'a std::path::Path, &'a UnitTrace)>,
+) -> TraceMatrix {
+    let mut matrix = TraceMatrix::default();
+
+    for (path, trace) in units {
+        for tag in &trace.tags {
+            matrix
+                .requirement_to_tags
+                .entry(tag.requirement_id.clone())
+                .or_default()
+                .push(tag.clone());
+        }
+        for name in &trace.untagged_functions {
+            matrix.untagged_functions.push((path.to_path_buf(), name.clone()));
+        }
+    }
+
+    matrix
+}
@@ -0,0 +1,137 @@
+//! Version-aware ordering for requirement and finding identifiers.
+//!
+//! Lexicographic sorting puts `REQ-10` before `REQ-2`, which is wrong
+//! for trace matrices and coverage summaries. This module splits each
+//! ID into alternating runs of digits and non-digits and compares those
+//! runs pairwise, so `REQ-2 < REQ-10 < REQ-10a`.
+
+use std::cmp::Ordering;
+
+/// One maximal run of either all-digit or all-non-digit characters.
+enum Run<'a> {
+    Digits(&'a str),
+    Other(&'a str),
+}
+
+fn split_runs(s: &str) -> Vec<Run<'_>> {
+    let mut runs = Vec::new();
+    let bytes = s.as_bytes();
+    let mut start = 0;
+    while start < bytes.len() {
+        let is_digit = bytes[start].is_ascii_digit();
+        let mut end = start + 1;
+        while end < bytes.len() && bytes[end].is_ascii_digit() == is_digit {
+            end += 1;
+        }
+        runs.push(if is_digit {
+            Run::Digits(&s[start..end])
+        } else {
+            Run::Other(&s[start..end])
+        });
+        start = end;
+    }
+    runs
+}
+
+/// Compares two digit runs numerically, ignoring leading zeros; if the
+/// numeric values are equal, the run with fewer leading zeros sorts
+/// first.
+fn compare_digit_runs(a: &str, b: &str) -> Ordering {
+    let a_trimmed = a.trim_start_matches('0');
+    let b_trimmed = b.trim_start_matches('0');
+
+    match a_trimmed.len().cmp(&b_trimmed.len()) {
+        Ordering::Equal => a_trimmed.cmp(b_trimmed).then_with(|| {
+            let a_leading_zeros = a.len() - a_trimmed.len();
+            let b_leading_zeros = b.len() - b_trimmed.len();
+            a_leading_zeros.cmp(&b_leading_zeros)
+        }),
+        other => other,
+    }
+}
+
+/// Compares two non-digit runs byte-by-byte: at each position, a
+/// non-lowercase character orders before a lowercase one; only when
+/// that rank ties at a position do the raw bytes at that position
+/// break the tie. Shorter-but-equal-so-far runs sort first.
+fn compare_other_runs(a: &str, b: &str) -> Ordering {
+    let rank = |c: u8| u8::from(c.is_ascii_lowercase());
+    for (&byte_a, &byte_b) in a.as_bytes().iter().zip(b.as_bytes().iter()) {
+        match rank(byte_a).cmp(&rank(byte_b)) {
+            Ordering::Equal => {}
+            other => return other,
+        }
+        match byte_a.cmp(&byte_b) {
+            Ordering::Equal => {}
+            other => return other,
+        }
+    }
+    a.len().cmp(&b.len())
+}
+
+/// Compares two identifiers using version-sort semantics: a digit run
+/// sorts before a non-digit run at the same position.
+pub fn compare(a: &str, b: &str) -> Ordering {
+    let a_runs = split_runs(a);
+    let b_runs = split_runs(b);
+
+    for pair in a_runs.iter().zip(b_runs.iter()) {
+        let ordering = match pair {
+            (Run::Digits(a), Run::Digits(b)) => compare_digit_runs(a, b),
+            (Run::Other(a), Run::Other(b)) => compare_other_runs(a, b),
+            (Run::Digits(_), Run::Other(_)) => Ordering::Less,
+            (Run::Other(_), Run::Digits(_)) => Ordering::Greater,
+        };
+        if ordering != Ordering::Equal {
+            return ordering;
+        }
+    }
+
+    a_runs.len().cmp(&b_runs.len())
+}
+
+/// Sorts IDs in place using [`compare`].
+pub fn sort_ids(ids: &mut [String]) {
+    ids.sort_by(|a, b| compare(a, b));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn numeric_runs_order_by_value_not_text() {
+        assert_eq!(compare("REQ-2", "REQ-10"), Ordering::Less);
+        assert_eq!(compare("REQ-10", "REQ-2"), Ordering::Greater);
+        assert_eq!(compare("REQ-10", "REQ-10a"), Ordering::Less);
+    }
+
+    #[test]
+    fn leading_zeros_break_numeric_ties_by_fewer_zeros_first() {
+        assert_eq!(compare("REQ-02", "REQ-2"), Ordering::Greater);
+        assert_eq!(compare("REQ-2", "REQ-02"), Ordering::Less);
+    }
+
+    #[test]
+    fn full_sort_is_version_ordered() {
+        let mut ids = vec![
+            "REQ-10a".to_string(),
+            "REQ-2".to_string(),
+            "REQ-10".to_string(),
+        ];
+        sort_ids(&mut ids);
+        assert_eq!(ids, vec!["REQ-2", "REQ-10", "REQ-10a"]);
+    }
+
+    #[test]
+    fn non_digit_runs_compare_positionally_rank_then_byte() {
+        // Equal rank at position 0 ('a' and 'b' both lowercase), so the
+        // raw byte at that position decides — not the rank sequence.
+        assert_eq!(compare("ac", "bB"), Ordering::Less);
+    }
+
+    #[test]
+    fn non_lowercase_orders_before_lowercase_at_same_position() {
+        assert_eq!(compare("Aa", "aa"), Ordering::Less);
+    }
+}